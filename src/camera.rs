@@ -0,0 +1,71 @@
+use macroquad::prelude::*;
+
+/// Reference resolution the playfield is designed at. The camera always
+/// shows exactly this much world space, scaled and letterboxed to fit
+/// whatever the actual window size is, so the game doesn't get bigger or
+/// smaller just because the window was resized.
+const VIRTUAL_WIDTH: f32 = 1280.0;
+const VIRTUAL_HEIGHT: f32 = 720.0;
+
+/// Half-extents of the box the target can move within before the camera
+/// starts following it.
+const DEAD_ZONE_HALF: Vec2 = Vec2::new(80.0, 50.0);
+/// Exponential smoothing rate: higher catches up to the target faster.
+const FOLLOW_SMOOTHING: f32 = 8.0;
+
+/// Follows a target with a dead-zone and smoothing instead of snapping to
+/// it every frame, and renders through a fixed virtual resolution so the
+/// playfield stays a constant size (letterboxed) regardless of window size.
+pub(crate) struct GameCamera {
+    pos: Vec2,
+}
+
+impl GameCamera {
+    pub(crate) fn new(start: Vec2) -> Self {
+        Self { pos: start }
+    }
+
+    /// Moves the camera toward `target`, only when it leaves the dead-zone,
+    /// then clamps the result so the camera never shows past the level's
+    /// horizontal bounds.
+    pub(crate) fn update(&mut self, target: Vec2, level_width: f32, dt: f32) {
+        let mut pursue = self.pos;
+        let delta = target - self.pos;
+
+        if delta.x > DEAD_ZONE_HALF.x {
+            pursue.x = target.x - DEAD_ZONE_HALF.x;
+        } else if delta.x < -DEAD_ZONE_HALF.x {
+            pursue.x = target.x + DEAD_ZONE_HALF.x;
+        }
+
+        if delta.y > DEAD_ZONE_HALF.y {
+            pursue.y = target.y - DEAD_ZONE_HALF.y;
+        } else if delta.y < -DEAD_ZONE_HALF.y {
+            pursue.y = target.y + DEAD_ZONE_HALF.y;
+        }
+
+        let alpha = 1.0 - (-FOLLOW_SMOOTHING * dt).exp();
+        self.pos += (pursue - self.pos) * alpha;
+
+        let half_width = VIRTUAL_WIDTH / 2.0;
+        self.pos.x = self.pos.x.clamp(half_width, (level_width - half_width).max(half_width));
+    }
+
+    /// Builds the `Camera2D` for the current frame: a fixed virtual-resolution
+    /// zoom inside a viewport centered and scaled to fit the window, so the
+    /// unused space letterboxes instead of stretching the playfield.
+    pub(crate) fn to_camera2d(&self) -> Camera2D {
+        let scale = (screen_width() / VIRTUAL_WIDTH).min(screen_height() / VIRTUAL_HEIGHT);
+        let viewport_w = VIRTUAL_WIDTH * scale;
+        let viewport_h = VIRTUAL_HEIGHT * scale;
+        let viewport_x = (screen_width() - viewport_w) / 2.0;
+        let viewport_y = (screen_height() - viewport_h) / 2.0;
+
+        Camera2D {
+            target: self.pos,
+            zoom: vec2(2.0 / VIRTUAL_WIDTH, 2.0 / VIRTUAL_HEIGHT),
+            viewport: Some((viewport_x as i32, viewport_y as i32, viewport_w as i32, viewport_h as i32)),
+            ..Default::default()
+        }
+    }
+}
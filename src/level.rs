@@ -0,0 +1,110 @@
+use macroquad::prelude::*;
+
+use crate::{Enemy, Platform};
+
+/// Side length of a single grid cell in the ASCII level format.
+pub(crate) const TILE_SIZE: f32 = 40.0;
+
+/// A parsed level: where the player starts, the platforms and enemies to
+/// spawn, and the level's total pixel width (used to clamp the camera
+/// instead of guessing from the window size).
+pub(crate) struct Level {
+    pub(crate) player_start: Vec2,
+    pub(crate) platforms: Vec<Platform>,
+    pub(crate) enemies: Vec<Enemy>,
+    pub(crate) width: f32,
+}
+
+impl Level {
+    /// Loads and parses a level file. See `parse` for the grid format.
+    pub(crate) fn load(path: &str) -> Result<Level, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read level file '{path}': {err}"))?;
+        Ok(Self::parse(&contents))
+    }
+
+    /// Parses a simple grid-based ASCII level: `#` is a solid tile, `P` is
+    /// the player spawn, `E` is an enemy spawn, and `.` (or any other
+    /// character) is empty space. Adjacent solid tiles within a row are
+    /// merged into a single wide platform rect to keep the collision list
+    /// small.
+    fn parse(contents: &str) -> Level {
+        let mut platforms = Vec::new();
+        let mut enemies = Vec::new();
+        let mut player_start = Vec2::ZERO;
+        let mut width: f32 = 0.0;
+
+        for (row, line) in contents.lines().enumerate() {
+            let y = row as f32 * TILE_SIZE;
+            let chars: Vec<char> = line.chars().collect();
+            width = width.max(chars.len() as f32 * TILE_SIZE);
+
+            let mut run_start: Option<usize> = None;
+            for (col, &tile) in chars.iter().enumerate() {
+                if tile == '#' {
+                    run_start.get_or_insert(col);
+                    continue;
+                }
+
+                if let Some(start) = run_start.take() {
+                    platforms.push(solid_run(start, col, y));
+                }
+
+                match tile {
+                    'P' => player_start = Vec2::new(col as f32 * TILE_SIZE, y),
+                    'E' => enemies.push(Enemy::new(col as f32 * TILE_SIZE, y)),
+                    _ => {}
+                }
+            }
+
+            if let Some(start) = run_start.take() {
+                platforms.push(solid_run(start, chars.len(), y));
+            }
+        }
+
+        Level { player_start, platforms, enemies, width }
+    }
+}
+
+/// Builds the merged platform rect for a run of solid tiles spanning
+/// `[start_col, end_col)` in a row at pixel height `y`.
+fn solid_run(start_col: usize, end_col: usize, y: f32) -> Platform {
+    Platform {
+        rect: Rect::new(
+            start_col as f32 * TILE_SIZE,
+            y,
+            (end_col - start_col) as f32 * TILE_SIZE,
+            TILE_SIZE,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_adjacent_solid_tiles_into_one_platform() {
+        let grid = "\
+.P..E.
+...#..
+..####
+######";
+
+        let level = Level::parse(grid);
+
+        assert_eq!(level.player_start, Vec2::new(1.0 * TILE_SIZE, 0.0));
+        assert_eq!(level.enemies.len(), 1);
+        assert_eq!(level.width, 6.0 * TILE_SIZE);
+
+        let rects: Vec<Rect> = level.platforms.iter().map(|p| p.rect).collect();
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new(3.0 * TILE_SIZE, 1.0 * TILE_SIZE, 1.0 * TILE_SIZE, TILE_SIZE),
+                Rect::new(2.0 * TILE_SIZE, 2.0 * TILE_SIZE, 4.0 * TILE_SIZE, TILE_SIZE),
+                Rect::new(0.0, 3.0 * TILE_SIZE, 6.0 * TILE_SIZE, TILE_SIZE),
+            ]
+        );
+    }
+}
@@ -1,5 +1,12 @@
 use macroquad::prelude::*;
 
+mod camera;
+mod level;
+mod projectile;
+use camera::GameCamera;
+use level::Level;
+use projectile::{Angle, Projectile};
+
 const PLAYER_SIZE: Vec2 = Vec2::new(32.0, 48.0);
 const PLAYER_SPEED: f32 = 200.0;
 const JUMP_STRENGTH: f32 = 500.0;
@@ -7,38 +14,227 @@ const GRAVITY: f32 = 980.0;
 const ENEMY_SIZE: Vec2 = Vec2::new(32.0, 32.0);
 const ENEMY_SPEED: f32 = 50.0;
 
+const PLAYER_MAX_HP: i32 = 3;
+/// How long the player is immune to contact damage after being hit, so
+/// standing inside an enemy doesn't drain all of its health in one frame.
+const CONTACT_INVULN_TIME: f32 = 1.0;
+const ENEMY_MAX_HP: i32 = 2;
+const ENEMY_CONTACT_DAMAGE: i32 = 1;
+
+/// Fixed physics step. Jump height, patrol speed, and collision behaviour
+/// are all defined in terms of this step, so simulation results don't
+/// depend on the render framerate.
+const TIMESTEP: f32 = 1.0 / 60.0;
+/// Upper bound on how much real time a single frame is allowed to feed into
+/// the accumulator, so a long pause (tab switch, breakpoint) can't queue up
+/// a huge backlog of fixed updates and stall the game (the "spiral of death").
+const MAX_FRAME_TIME: f32 = 0.25;
+
+/// How long after walking off a ledge a jump still counts as grounded.
+const COYOTE_TIME: f32 = 0.1;
+/// How long a jump press is remembered so pressing it just before landing
+/// still fires on the landing frame.
+const JUMP_BUFFER_TIME: f32 = 0.1;
+/// Extra jumps allowed while airborne (i.e. 1 = a single double jump).
+const MAX_AIR_JUMPS: u32 = 1;
+/// Terminal fall speed while sliding down a wall.
+const WALL_SLIDE_SPEED: f32 = 100.0;
+/// Velocity kick away from the wall on a wall jump, combined with the usual
+/// `JUMP_STRENGTH` upward component.
+const WALL_JUMP_PUSH: f32 = 300.0;
+/// How long after a wall jump the kick overrides horizontal input, so the
+/// push isn't immediately overwritten by the next sub-step's `acceleration.x`
+/// before the player has actually cleared the wall.
+const WALL_JUMP_LOCK_TIME: f32 = 0.15;
+/// How far the wall probe rects extend out from the player's sides.
+const WALL_PROBE_DEPTH: f32 = 4.0;
+
 struct Player {
     pos: Vec2,
+    prev_pos: Vec2,
     velocity: Vec2,
     grounded: bool,
     facing_right: bool,
+    coyote_timer: f32,
+    jump_buffer_timer: f32,
+    wall_jump_lock_timer: f32,
+    air_jumps_remaining: u32,
+    touching_wall_left: bool,
+    touching_wall_right: bool,
+    hp: i32,
+    invuln_timer: f32,
 }
 
-struct Platform {
-    rect: Rect,
+pub(crate) struct Platform {
+    pub(crate) rect: Rect,
 }
 
-struct Enemy {
+pub(crate) struct Enemy {
     pos: Vec2,
+    prev_pos: Vec2,
     velocity: Vec2,
     moving_right: bool,
     rect: Rect,
+    hp: i32,
+}
+
+/// Computes the entry/exit time of a swept box along a single axis, in units
+/// of the displacement (0 = start of frame, 1 = end of frame). A stationary
+/// axis (`vel == 0`) can never enter or leave, so it spans the whole frame
+/// *if* the boxes already overlap on that axis; otherwise they never meet on
+/// it, no matter what the other axis does, so it must report no collision.
+fn axis_entry_exit(vel: f32, box_near: f32, box_far: f32, plat_near: f32, plat_far: f32) -> (f32, f32) {
+    if vel == 0.0 {
+        if box_far <= plat_near || box_near >= plat_far {
+            (f32::INFINITY, f32::INFINITY)
+        } else {
+            (f32::NEG_INFINITY, f32::INFINITY)
+        }
+    } else if vel > 0.0 {
+        ((plat_near - box_far) / vel, (plat_far - box_near) / vel)
+    } else {
+        ((plat_far - box_near) / vel, (plat_near - box_far) / vel)
+    }
+}
+
+/// Swept-AABB test: sweeps `box_rect` along `displacement` and checks for a
+/// collision with `platform`. Returns the fraction of `displacement` at
+/// which the collision first occurs, together with the surface normal of
+/// the face that was hit (only ever axis-aligned, since rects don't rotate).
+fn swept_aabb(box_rect: Rect, displacement: Vec2, platform: Rect) -> Option<(f32, Vec2)> {
+    let (entry_x, exit_x) = axis_entry_exit(
+        displacement.x,
+        box_rect.x,
+        box_rect.right(),
+        platform.x,
+        platform.right(),
+    );
+    let (entry_y, exit_y) = axis_entry_exit(
+        displacement.y,
+        box_rect.y,
+        box_rect.bottom(),
+        platform.y,
+        platform.bottom(),
+    );
+
+    let entry_time = entry_x.max(entry_y);
+    let exit_time = exit_x.min(exit_y);
+
+    if entry_time > exit_time || (entry_x < 0.0 && entry_y < 0.0) || entry_time > 1.0 {
+        return None;
+    }
+
+    let normal = if entry_x > entry_y {
+        vec2(if displacement.x > 0.0 { -1.0 } else { 1.0 }, 0.0)
+    } else {
+        vec2(0.0, if displacement.y > 0.0 { -1.0 } else { 1.0 })
+    };
+
+    Some((entry_time.max(0.0), normal))
+}
+
+/// Moves a box of size `(rect.w, rect.h)` starting at `rect.x/y` by
+/// `displacement`, resolving collisions against `platforms` with repeated
+/// swept-AABB tests so the entity slides along a surface instead of
+/// tunnelling through it. Sweeps up to twice: the full displacement, then
+/// what is left of it after the first hit, so corners and stacked platforms
+/// resolve in the same frame.
+/// Returns the resolved position, the velocity with the hit axis zeroed,
+/// and whether the entity came to rest on top of a platform.
+fn resolve_sweep(rect: Rect, mut displacement: Vec2, mut velocity: Vec2, platforms: &[Platform]) -> (Vec2, Vec2, bool) {
+    let mut pos = vec2(rect.x, rect.y);
+    let mut grounded = false;
+
+    for _ in 0..2 {
+        if displacement == Vec2::ZERO {
+            break;
+        }
+
+        let current = Rect::new(pos.x, pos.y, rect.w, rect.h);
+        let mut closest: Option<(f32, Vec2)> = None;
+        for platform in platforms {
+            if let Some(hit) = swept_aabb(current, displacement, platform.rect) {
+                if closest.is_none_or(|(t, _)| hit.0 < t) {
+                    closest = Some(hit);
+                }
+            }
+        }
+
+        match closest {
+            Some((t, normal)) => {
+                pos += displacement * t;
+                let remaining = displacement * (1.0 - t);
+
+                if normal.x != 0.0 {
+                    velocity.x = 0.0;
+                    displacement = vec2(0.0, remaining.y);
+                } else {
+                    velocity.y = 0.0;
+                    if normal.y < 0.0 {
+                        grounded = true;
+                    }
+                    displacement = vec2(remaining.x, 0.0);
+                }
+            }
+            None => {
+                pos += displacement;
+                displacement = Vec2::ZERO;
+            }
+        }
+    }
+
+    pos += displacement;
+    (pos, velocity, grounded)
 }
 
 impl Player {
-    fn new() -> Self {
+    fn new(pos: Vec2) -> Self {
         Self {
-            pos: Vec2::new(100.0, 100.0),
+            pos,
+            prev_pos: pos,
             velocity: Vec2::ZERO,
             grounded: false,
             facing_right: true,
+            coyote_timer: 0.0,
+            jump_buffer_timer: 0.0,
+            wall_jump_lock_timer: 0.0,
+            air_jumps_remaining: MAX_AIR_JUMPS,
+            touching_wall_left: false,
+            touching_wall_right: false,
+            hp: PLAYER_MAX_HP,
+            invuln_timer: 0.0,
         }
     }
 
-    fn update(&mut self, dt: f32, platforms: &[Platform]) {
+    /// Applies contact damage unless the player is still invulnerable from
+    /// a previous hit.
+    fn take_damage(&mut self, amount: i32) {
+        if self.invuln_timer > 0.0 {
+            return;
+        }
+        self.hp -= amount;
+        self.invuln_timer = CONTACT_INVULN_TIME;
+    }
+
+    /// Advances the player by exactly one `TIMESTEP`. Called from the fixed
+    /// update loop in `main`, never with a raw frame `dt`, so physics stays
+    /// reproducible regardless of render framerate. `jump_pressed` is the
+    /// jump-key edge for the *frame*, not the sub-step — callers must sample
+    /// it once per frame and pass `true` on at most one sub-step, or a
+    /// single tap fires once per sub-step instead of once per press.
+    fn update(&mut self, dt: f32, platforms: &[Platform], jump_pressed: bool) {
+        self.prev_pos = self.pos;
+
+        // Tick the forgiveness timers down; they get refreshed below when
+        // the condition they track is actually met.
+        self.coyote_timer = (self.coyote_timer - dt).max(0.0);
+        self.jump_buffer_timer = (self.jump_buffer_timer - dt).max(0.0);
+        self.wall_jump_lock_timer = (self.wall_jump_lock_timer - dt).max(0.0);
+        self.invuln_timer = (self.invuln_timer - dt).max(0.0);
+
         // Horizontal movement
         let mut acceleration = Vec2::ZERO;
-        
+
         if is_key_down(KeyCode::A) || is_key_down(KeyCode::Left) {
             acceleration.x -= PLAYER_SPEED;
             self.facing_right = false;
@@ -48,61 +244,82 @@ impl Player {
             self.facing_right = true;
         }
 
-        // Jumping
-        if (is_key_pressed(KeyCode::W) || is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::Space)) 
-            && self.grounded {
-            self.velocity.y = -JUMP_STRENGTH;
-            self.grounded = false;
+        // Buffer the jump press instead of requiring it land exactly on a
+        // grounded frame, so a jump pressed just before touchdown still
+        // fires on the landing frame.
+        if jump_pressed {
+            self.jump_buffer_timer = JUMP_BUFFER_TIME;
         }
 
         // Apply gravity
         self.velocity.y += GRAVITY * dt;
-        
-        // Apply horizontal movement
-        self.velocity.x = acceleration.x;
-
-        // Update position
-        let new_pos = self.pos + self.velocity * dt;
 
-        // Check collisions with platforms
-        let player_rect = Rect::new(new_pos.x, new_pos.y, PLAYER_SIZE.x, PLAYER_SIZE.y);
-        self.grounded = false;
+        // Apply horizontal movement, unless a wall jump's kick is still in
+        // its lock window - otherwise this overwrites the kick on the very
+        // next sub-step and the player barely leaves the wall.
+        if self.wall_jump_lock_timer <= 0.0 {
+            self.velocity.x = acceleration.x;
+        }
 
-        let mut final_pos = new_pos;
+        // Probe just past each side of the player for a wall, the same way
+        // the enemy probes just past its feet for a ledge.
+        let rect = self.rect();
+        let left_probe = Rect::new(rect.x - WALL_PROBE_DEPTH, rect.y, WALL_PROBE_DEPTH, rect.h);
+        let right_probe = Rect::new(rect.right(), rect.y, WALL_PROBE_DEPTH, rect.h);
+        self.touching_wall_left = platforms.iter().any(|p| p.rect.overlaps(&left_probe));
+        self.touching_wall_right = platforms.iter().any(|p| p.rect.overlaps(&right_probe));
+        let touching_wall = !self.grounded && (self.touching_wall_left || self.touching_wall_right);
+
+        if touching_wall && self.velocity.y > 0.0 {
+            // Wall slide: cap the fall speed while pressed against a wall.
+            self.velocity.y = self.velocity.y.min(WALL_SLIDE_SPEED);
+        }
 
-        for platform in platforms {
-            if let Some(intersection) = player_rect.intersect(platform.rect) {
-                // Resolve collision
-                if self.velocity.y > 0.0 && intersection.y < intersection.x && intersection.y < intersection.w {
-                    // Landing on top of platform
-                    final_pos.y = platform.rect.y - PLAYER_SIZE.y;
-                    self.velocity.y = 0.0;
-                    self.grounded = true;
-                } else if self.velocity.y < 0.0 && intersection.y > intersection.x {
-                    // Hitting platform from below
-                    final_pos.y = platform.rect.bottom();
-                    self.velocity.y = 0.0;
-                } else if self.velocity.x > 0.0 {
-                    // Hitting from left
-                    final_pos.x = platform.rect.x - PLAYER_SIZE.x;
-                    self.velocity.x = 0.0;
-                } else if self.velocity.x < 0.0 {
-                    // Hitting from right
-                    final_pos.x = platform.rect.right();
-                    self.velocity.x = 0.0;
-                }
-            }
+        if self.jump_buffer_timer > 0.0 && touching_wall {
+            // Wall jump: kick up and away from the wall we're sliding on.
+            self.velocity.y = -JUMP_STRENGTH;
+            self.velocity.x = if self.touching_wall_left { WALL_JUMP_PUSH } else { -WALL_JUMP_PUSH };
+            self.wall_jump_lock_timer = WALL_JUMP_LOCK_TIME;
+            self.jump_buffer_timer = 0.0;
+            self.coyote_timer = 0.0;
+        } else if self.jump_buffer_timer > 0.0 && self.grounded {
+            // Grounded jump.
+            self.velocity.y = -JUMP_STRENGTH;
+            self.grounded = false;
+            self.jump_buffer_timer = 0.0;
+            self.coyote_timer = 0.0;
+        } else if self.jump_buffer_timer > 0.0 && self.coyote_timer > 0.0 {
+            // Coyote-time jump just after leaving a ledge. Still the jump
+            // that takes the player off the ground, so it draws from the
+            // same air-jump budget as the double jump instead of granting
+            // a free extra jump on top of it.
+            self.velocity.y = -JUMP_STRENGTH;
+            self.air_jumps_remaining = self.air_jumps_remaining.saturating_sub(1);
+            self.jump_buffer_timer = 0.0;
+            self.coyote_timer = 0.0;
+        } else if self.jump_buffer_timer > 0.0 && self.air_jumps_remaining > 0 {
+            // Double jump.
+            self.velocity.y = -JUMP_STRENGTH;
+            self.air_jumps_remaining -= 1;
+            self.jump_buffer_timer = 0.0;
         }
 
-        self.pos = final_pos;
+        // Sweep the player's displacement against the platforms instead of
+        // checking the post-move overlap, so fast falls can't tunnel through
+        // thin platforms and overlapping multiple platforms resolves to the
+        // earliest collision.
+        let displacement = self.velocity * dt;
+        let (final_pos, resolved_velocity, grounded) = resolve_sweep(self.rect(), displacement, self.velocity, platforms);
+        self.velocity = resolved_velocity;
+        self.grounded = grounded;
 
-        // Ground collision (prevent falling through bottom of screen)
-        if self.pos.y > screen_height() - PLAYER_SIZE.y {
-            self.pos.y = screen_height() - PLAYER_SIZE.y;
-            self.velocity.y = 0.0;
-            self.grounded = true;
+        if self.grounded {
+            self.coyote_timer = COYOTE_TIME;
+            self.air_jumps_remaining = MAX_AIR_JUMPS;
         }
 
+        self.pos = final_pos;
+
         // Apply friction when grounded
         if self.grounded {
             self.velocity.x *= 0.8;
@@ -113,34 +330,44 @@ impl Player {
         Rect::new(self.pos.x, self.pos.y, PLAYER_SIZE.x, PLAYER_SIZE.y)
     }
 
-    fn draw(&self) {
+    /// Draws the player at a position interpolated between `prev_pos` and
+    /// `pos` using `alpha` (the accumulator's fraction of a `TIMESTEP`), so
+    /// motion looks smooth even though physics only advances in fixed steps.
+    fn draw(&self, alpha: f32) {
+        let draw_pos = self.prev_pos.lerp(self.pos, alpha);
+
         // Simple player rectangle with direction indicator
         let color = if self.facing_right { BLUE } else { LIGHTGRAY };
-        draw_rectangle(self.pos.x, self.pos.y, PLAYER_SIZE.x, PLAYER_SIZE.y, color);
-        
+        draw_rectangle(draw_pos.x, draw_pos.y, PLAYER_SIZE.x, PLAYER_SIZE.y, color);
+
         // Draw a simple face/eyes
         if self.facing_right {
-            draw_circle(self.pos.x + 24.0, self.pos.y + 12.0, 3.0, BLACK);
-            draw_circle(self.pos.x + 20.0, self.pos.y + 12.0, 3.0, BLACK);
+            draw_circle(draw_pos.x + 24.0, draw_pos.y + 12.0, 3.0, BLACK);
+            draw_circle(draw_pos.x + 20.0, draw_pos.y + 12.0, 3.0, BLACK);
         } else {
-            draw_circle(self.pos.x + 8.0, self.pos.y + 12.0, 3.0, BLACK);
-            draw_circle(self.pos.x + 12.0, self.pos.y + 12.0, 3.0, BLACK);
+            draw_circle(draw_pos.x + 8.0, draw_pos.y + 12.0, 3.0, BLACK);
+            draw_circle(draw_pos.x + 12.0, draw_pos.y + 12.0, 3.0, BLACK);
         }
     }
 }
 
 impl Enemy {
-    fn new(x: f32, y: f32) -> Self {
+    pub(crate) fn new(x: f32, y: f32) -> Self {
         let pos = Vec2::new(x, y);
         Self {
             pos,
+            prev_pos: pos,
             velocity: Vec2::new(ENEMY_SPEED, 0.0),
             moving_right: true,
             rect: Rect::new(pos.x, pos.y, ENEMY_SIZE.x, ENEMY_SIZE.y),
+            hp: ENEMY_MAX_HP,
         }
     }
 
+    /// Advances the enemy by exactly one `TIMESTEP`; see `Player::update`.
     fn update(&mut self, dt: f32, platforms: &[Platform]) {
+        self.prev_pos = self.pos;
+
         // Simple patrol behavior - move back and forth
         if self.moving_right {
             self.velocity.x = ENEMY_SPEED;
@@ -169,97 +396,111 @@ impl Enemy {
         // Apply gravity
         self.velocity.y += GRAVITY * dt;
 
-        // Update position
-        let new_pos = self.pos + self.velocity * dt;
-        let enemy_rect = Rect::new(new_pos.x, new_pos.y, ENEMY_SIZE.x, ENEMY_SIZE.y);
-
-        let mut final_pos = new_pos;
-        
-        // Check collisions with platforms
-        for platform in platforms {
-            if let Some(intersection) = enemy_rect.intersect(platform.rect) {
-                if self.velocity.y > 0.0 && intersection.y < intersection.x {
-                    // Landing on platform
-                    final_pos.y = platform.rect.y - ENEMY_SIZE.y;
-                    self.velocity.y = 0.0;
-                }
-            }
-        }
-
-        // Ground collision
-        if final_pos.y > screen_height() - ENEMY_SIZE.y {
-            final_pos.y = screen_height() - ENEMY_SIZE.y;
-            self.velocity.y = 0.0;
-        }
+        // Sweep against platforms instead of resolving post-move overlap,
+        // same as the player, so enemies can't fall through thin platforms.
+        let displacement = self.velocity * dt;
+        let (final_pos, resolved_velocity, _grounded) = resolve_sweep(self.rect, displacement, self.velocity, platforms);
+        self.velocity = resolved_velocity;
 
         self.pos = final_pos;
         self.rect = Rect::new(self.pos.x, self.pos.y, ENEMY_SIZE.x, ENEMY_SIZE.y);
     }
 
-    fn draw(&self) {
-        draw_rectangle(self.pos.x, self.pos.y, ENEMY_SIZE.x, ENEMY_SIZE.y, RED);
+    /// Draws the enemy interpolated between `prev_pos` and `pos`; see
+    /// `Player::draw`.
+    fn draw(&self, alpha: f32) {
+        let draw_pos = self.prev_pos.lerp(self.pos, alpha);
+
+        draw_rectangle(draw_pos.x, draw_pos.y, ENEMY_SIZE.x, ENEMY_SIZE.y, RED);
         // Draw simple eyes
-        draw_circle(self.pos.x + 10.0, self.pos.y + 10.0, 3.0, YELLOW);
-        draw_circle(self.pos.x + 22.0, self.pos.y + 10.0, 3.0, YELLOW);
+        draw_circle(draw_pos.x + 10.0, draw_pos.y + 10.0, 3.0, YELLOW);
+        draw_circle(draw_pos.x + 22.0, draw_pos.y + 10.0, 3.0, YELLOW);
     }
 }
 
 #[macroquad::main("MapleStory-style Side Scroller")]
 async fn main() {
-    let mut player = Player::new();
-    
-    // Create platforms
-    let mut platforms = vec![
-        Platform {
-            rect: Rect::new(0.0, screen_height() - 40.0, screen_width(), 40.0),
-        },
-        Platform {
-            rect: Rect::new(300.0, screen_height() - 200.0, 200.0, 20.0),
-        },
-        Platform {
-            rect: Rect::new(600.0, screen_height() - 150.0, 150.0, 20.0),
-        },
-        Platform {
-            rect: Rect::new(900.0, screen_height() - 300.0, 200.0, 20.0),
-        },
-        Platform {
-            rect: Rect::new(1200.0, screen_height() - 250.0, 180.0, 20.0),
-        },
-    ];
-
-    // Create enemies
-    let mut enemies = vec![
-        Enemy::new(400.0, screen_height() - 250.0),
-        Enemy::new(700.0, screen_height() - 200.0),
-        Enemy::new(1100.0, screen_height() - 350.0),
-    ];
-
-    let mut camera_x = 0.0;
+    let level = Level::load("levels/level1.txt").expect("failed to load level");
+    let level_width = level.width;
+
+    let mut player = Player::new(level.player_start);
+    let platforms = level.platforms;
+    let mut enemies = level.enemies;
+    let mut projectiles: Vec<Projectile> = Vec::new();
+
+    let mut game_camera = GameCamera::new(level.player_start);
+    let mut accumulator = 0.0;
 
     loop {
         clear_background(SKYBLUE);
 
-        let dt = get_frame_time();
+        // Feed real frame time into the accumulator and drain it in fixed
+        // TIMESTEP chunks, so jump height and patrol speed stop depending on
+        // framerate. Clamp the frame time first so a long pause (e.g. the
+        // tab losing focus) can't queue up a spiral-of-death backlog.
+        accumulator += get_frame_time().min(MAX_FRAME_TIME);
+
+        // Sample the jump-key edge once per real frame, not once per fixed
+        // sub-step: `is_key_pressed` stays true for every sub-step a frame
+        // drains, so polling it inside the loop fires a second jump (or a
+        // wall/double jump) from a single tap whenever a frame drains 2+ steps.
+        let mut jump_pressed = is_key_pressed(KeyCode::W) || is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::Space);
+        // Same reasoning for the fire key: sample the edge once per frame
+        // and consume it on at most one sub-step, or one tap fires a
+        // projectile per sub-step instead of one per press.
+        let mut fire_pressed = is_key_pressed(KeyCode::F);
+
+        while accumulator >= TIMESTEP {
+            player.update(TIMESTEP, &platforms, jump_pressed);
+            jump_pressed = false;
+
+            for enemy in &mut enemies {
+                enemy.update(TIMESTEP, &platforms);
+            }
 
-        // Update camera to follow player
-        camera_x = (player.pos.x - screen_width() / 2.0).max(0.0);
-        
-        // Set camera transform
-        set_camera(&Camera2D {
-            target: vec2(camera_x + screen_width() / 2.0, screen_height() / 2.0),
-            rotation: 0.0,
-            zoom: vec2(1.0, 1.0),
-            ..Default::default()
-        });
+            if fire_pressed {
+                let facing = if player.facing_right { 1.0 } else { -1.0 };
+                let spawn = vec2(
+                    player.pos.x + PLAYER_SIZE.x / 2.0 + facing * PLAYER_SIZE.x / 2.0,
+                    player.pos.y + PLAYER_SIZE.y / 2.0,
+                );
+                projectiles.push(Projectile::new(spawn, Angle::from_vec(vec2(facing, 0.0))));
+            }
+            fire_pressed = false;
+
+            projectiles.retain_mut(|projectile| projectile.update(TIMESTEP));
+
+            // Projectile vs. enemy: the first enemy a projectile overlaps
+            // takes the damage and the projectile is consumed.
+            projectiles.retain(|projectile| {
+                let hit_rect = projectile.rect();
+                match enemies.iter_mut().find(|enemy| enemy.rect.overlaps(&hit_rect)) {
+                    Some(enemy) => {
+                        enemy.hp -= 1;
+                        false
+                    }
+                    None => true,
+                }
+            });
+            enemies.retain(|enemy| enemy.hp > 0);
 
-        // Update player
-        player.update(dt, &platforms);
+            // Enemy contact damage.
+            for enemy in &enemies {
+                if player.rect().overlaps(&enemy.rect) {
+                    player.take_damage(ENEMY_CONTACT_DAMAGE);
+                }
+            }
 
-        // Update enemies
-        for enemy in &mut enemies {
-            enemy.update(dt, &platforms);
+            accumulator -= TIMESTEP;
         }
 
+        let alpha = accumulator / TIMESTEP;
+
+        // Follow the player with a dead-zone and smoothing instead of
+        // snapping the camera to them every frame.
+        game_camera.update(player.pos, level_width, get_frame_time());
+        set_camera(&game_camera.to_camera2d());
+
         // Draw platforms
         for platform in &platforms {
             draw_rectangle(platform.rect.x, platform.rect.y, platform.rect.w, platform.rect.h, GREEN);
@@ -268,11 +509,16 @@ async fn main() {
         }
 
         // Draw player
-        player.draw();
+        player.draw(alpha);
 
         // Draw enemies
         for enemy in &enemies {
-            enemy.draw();
+            enemy.draw(alpha);
+        }
+
+        // Draw projectiles
+        for projectile in &projectiles {
+            projectile.draw();
         }
 
         // Draw UI (should not be affected by camera)
@@ -284,7 +530,8 @@ async fn main() {
         });
 
         // Draw instructions
-        draw_text("WASD / Arrow Keys to move, Space to jump", 10.0, 30.0, 20.0, WHITE);
+        draw_text("WASD / Arrow Keys to move, Space to jump, F to shoot", 10.0, 30.0, 20.0, WHITE);
+        draw_text(format!("HP: {}", player.hp), 10.0, 55.0, 20.0, WHITE);
 
         next_frame().await;
     }
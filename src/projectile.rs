@@ -0,0 +1,57 @@
+use macroquad::prelude::*;
+
+const PROJECTILE_SPEED: f32 = 600.0;
+const PROJECTILE_LIFETIME: f32 = 1.5;
+const PROJECTILE_RADIUS: f32 = 5.0;
+
+/// A direction in radians, with conversions to and from the unit `Vec2`
+/// macroquad's movement and drawing calls expect.
+#[derive(Clone, Copy)]
+pub(crate) struct Angle(f32);
+
+impl Angle {
+    pub(crate) fn from_vec(direction: Vec2) -> Self {
+        Angle(direction.y.atan2(direction.x))
+    }
+
+    pub(crate) fn to_vec(self) -> Vec2 {
+        vec2(self.0.cos(), self.0.sin())
+    }
+}
+
+pub(crate) struct Projectile {
+    pub(crate) pos: Vec2,
+    velocity: Vec2,
+    lifetime: f32,
+}
+
+impl Projectile {
+    pub(crate) fn new(pos: Vec2, direction: Angle) -> Self {
+        Self {
+            pos,
+            velocity: direction.to_vec() * PROJECTILE_SPEED,
+            lifetime: PROJECTILE_LIFETIME,
+        }
+    }
+
+    /// Advances the projectile by `dt`. Returns `false` once its lifetime
+    /// has run out, so callers can drop it with `Vec::retain_mut`.
+    pub(crate) fn update(&mut self, dt: f32) -> bool {
+        self.pos += self.velocity * dt;
+        self.lifetime -= dt;
+        self.lifetime > 0.0
+    }
+
+    pub(crate) fn rect(&self) -> Rect {
+        Rect::new(
+            self.pos.x - PROJECTILE_RADIUS,
+            self.pos.y - PROJECTILE_RADIUS,
+            PROJECTILE_RADIUS * 2.0,
+            PROJECTILE_RADIUS * 2.0,
+        )
+    }
+
+    pub(crate) fn draw(&self) {
+        draw_circle(self.pos.x, self.pos.y, PROJECTILE_RADIUS, YELLOW);
+    }
+}